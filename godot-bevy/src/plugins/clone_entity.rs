@@ -0,0 +1,96 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::scene::SceneFilter;
+use std::any::TypeId;
+use tracing::warn;
+
+/// Copies every reflected component from `source` onto `destination` using
+/// the `AppTypeRegistry`, skipping types listed in `exclude` or not present
+/// in the registry.
+///
+/// The motivating use case is runtime prefab instancing: when a
+/// `GodotPackedScene` is duplicated, the gameplay components configured on
+/// the template entity are copied onto the new instance without hand-writing
+/// per-type copy code. Missing source entities or unregistered component
+/// types are downgraded to warnings so a partial template still clones what
+/// it can.
+pub struct CloneEntity {
+    pub source: Entity,
+    pub destination: Entity,
+    pub exclude: SceneFilter,
+}
+
+impl Command for CloneEntity {
+    fn apply(self, world: &mut World) {
+        // Walk the source entity's own components instead of the whole
+        // `AppTypeRegistry`, which would otherwise probe every registered
+        // type on every clone regardless of how many the entity actually has.
+        let type_ids: Vec<(TypeId, String)> = {
+            let Ok(source_ref) = world.get_entity(self.source) else {
+                warn!(source = ?self.source, "clone_entity: source entity does not exist; skipping");
+                return;
+            };
+            source_ref
+                .archetype()
+                .components()
+                .filter_map(|component_id| world.components().get_info(component_id))
+                .filter_map(|info| info.type_id().map(|type_id| (type_id, info.name().to_string())))
+                .collect()
+        };
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let reflect_components: Vec<_> = type_ids
+            .into_iter()
+            .filter(|(type_id, _)| self.exclude.is_allowed_by_id(*type_id))
+            .filter_map(|(type_id, name)| {
+                let registration = registry.get(type_id);
+                if registration.is_none() {
+                    warn!(component = %name, "clone_entity: component type is not registered; skipping");
+                }
+                registration
+            })
+            .filter_map(|registration| registration.data::<ReflectComponent>())
+            .collect();
+
+        for reflect_component in reflect_components {
+            let Ok(source_ref) = world.get_entity(self.source) else {
+                break;
+            };
+            let Some(value) = reflect_component.reflect(source_ref) else {
+                continue;
+            };
+            let cloned = value
+                .reflect_clone()
+                .map_or_else(|_| value.to_dynamic(), |cloned| cloned.into_partial_reflect());
+
+            let Ok(mut destination_mut) = world.get_entity_mut(self.destination) else {
+                warn!(destination = ?self.destination, "clone_entity: destination entity does not exist; aborting");
+                return;
+            };
+            reflect_component.apply_or_insert(&mut destination_mut, cloned.as_partial_reflect(), &registry);
+        }
+    }
+}
+
+/// Extension trait adding `clone_entity` to [`Commands`] for spawning a
+/// prefab-style copy of an existing entity.
+pub trait CommandsCloneEntityExt {
+    /// Spawns a fresh entity and queues a [`CloneEntity`] command copying
+    /// every reflected component from `source` onto it.
+    fn clone_entity(&mut self, source: Entity) -> EntityCommands;
+}
+
+impl CommandsCloneEntityExt for Commands<'_, '_> {
+    fn clone_entity(&mut self, source: Entity) -> EntityCommands {
+        let destination = self.spawn_empty().id();
+        self.queue(CloneEntity {
+            source,
+            destination,
+            exclude: SceneFilter::default(),
+        });
+        self.entity(destination)
+    }
+}
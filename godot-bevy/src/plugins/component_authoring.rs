@@ -0,0 +1,257 @@
+use crate::interop::GodotNodeHandle;
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use godot::prelude::*;
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+use std::any::TypeId;
+use std::fmt;
+use tracing::warn;
+
+/// Metadata key read from every spawned scene-tree node to author components
+/// from the Godot editor instead of Rust.
+///
+/// The value must be a RON map from fully-qualified type path to the RON
+/// representation of that type, e.g.:
+///
+/// ```ron
+/// { "my_game::Health": (max: 100.0, current: 100.0), "my_game::Faction": Enemy }
+/// ```
+pub const BEVY_COMPONENTS_META_KEY: &str = "bevy_components";
+
+/// Deserializes the `bevy_components` metadata on newly spawned scene-tree
+/// nodes into real Bevy components via reflection.
+///
+/// This is the editor-authoring counterpart to [`SceneTreeComponentRegistry`](crate::plugins::core::SceneTreeComponentRegistry):
+/// that registry attaches Rust-defined components to every scene-tree entity,
+/// while this plugin lets individual nodes opt into additional components by
+/// storing a RON blueprint as editor metadata.
+#[derive(Default)]
+pub struct GodotComponentAuthoringPlugin;
+
+impl Plugin for GodotComponentAuthoringPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, apply_metadata_components);
+    }
+}
+
+/// Command that inserts a single reflected component onto an entity, looking
+/// up its `ReflectComponent` hook by `TypeId` at apply time.
+struct InsertReflectedComponent {
+    entity: Entity,
+    type_id: TypeId,
+    value: Box<dyn PartialReflect>,
+}
+
+impl Command for InsertReflectedComponent {
+    fn apply(self, world: &mut World) {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let Some(registration) = registry.get(self.type_id) else {
+            return;
+        };
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            return;
+        };
+        let Ok(mut entity_mut) = world.get_entity_mut(self.entity) else {
+            return;
+        };
+
+        reflect_component.apply_or_insert(&mut entity_mut, self.value.as_partial_reflect(), &registry);
+    }
+}
+
+/// Reads the `bevy_components` RON blueprint off each freshly spawned node
+/// and queues a reflected insert for every entry it can resolve.
+///
+/// Nodes are processed in ascending order of their node-path depth so that
+/// parents are handled before children, matching the order scene-tree spawn
+/// already reconstructs the hierarchy in.
+fn apply_metadata_components(
+    mut commands: Commands,
+    type_registry: Res<AppTypeRegistry>,
+    spawned: Query<(Entity, &GodotNodeHandle), Added<GodotNodeHandle>>,
+) {
+    let mut spawned: Vec<_> = spawned.iter().collect();
+    spawned.sort_by_key(|(_, handle)| {
+        handle
+            .get::<Node>()
+            .get_path()
+            .to_string()
+            .matches('/')
+            .count()
+    });
+
+    for (entity, handle) in spawned {
+        let mut node = handle.get::<Node>();
+        let node_path = node.get_path().to_string();
+
+        if !node.has_meta(BEVY_COMPONENTS_META_KEY) {
+            continue;
+        }
+
+        let blueprint = match node.get_meta(BEVY_COMPONENTS_META_KEY).try_to::<GString>() {
+            Ok(blueprint) => blueprint.to_string(),
+            Err(err) => {
+                warn!(node = %node_path, error = %err, "bevy_components metadata is not a string; skipping node");
+                continue;
+            }
+        };
+
+        let registry = type_registry.read();
+        let mut deserializer = match ron::Deserializer::from_str(&blueprint) {
+            Ok(deserializer) => deserializer,
+            Err(err) => {
+                warn!(node = %node_path, error = %err, "malformed bevy_components metadata; skipping node");
+                continue;
+            }
+        };
+        let components = match (ComponentMapDeserializer {
+            registry: &registry,
+            node_path: &node_path,
+        })
+        .deserialize(&mut deserializer)
+        {
+            Ok(components) => components,
+            Err(err) => {
+                warn!(node = %node_path, error = %err, "malformed bevy_components metadata; skipping node");
+                continue;
+            }
+        };
+
+        for (type_id, value) in components {
+            commands.queue(InsertReflectedComponent {
+                entity,
+                type_id,
+                value,
+            });
+        }
+    }
+}
+
+/// Deserializes a `bevy_components` RON map directly into reflected values,
+/// looking up each entry's `TypedReflectDeserializer` off its own type path
+/// instead of going through a generic intermediate (e.g. `ron::Value`), which
+/// would lose RON's struct/tuple-struct/enum syntax and turn every
+/// non-primitive component into something `TypedReflectDeserializer` rejects.
+struct ComponentMapDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    node_path: &'a str,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for ComponentMapDeserializer<'a> {
+    type Value = Vec<(TypeId, Box<dyn PartialReflect>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ComponentMapVisitor {
+            registry: self.registry,
+            node_path: self.node_path,
+        })
+    }
+}
+
+struct ComponentMapVisitor<'a> {
+    registry: &'a TypeRegistry,
+    node_path: &'a str,
+}
+
+impl<'de, 'a> Visitor<'de> for ComponentMapVisitor<'a> {
+    type Value = Vec<(TypeId, Box<dyn PartialReflect>)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map from component type path to its RON representation")
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut components = Vec::new();
+        while let Some(type_path) = map.next_key::<String>()? {
+            let Some(registration) = self.registry.get_with_type_path(&type_path) else {
+                warn!(node = %self.node_path, type_path, "unregistered component type in bevy_components metadata; skipping");
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            if registration.data::<ReflectComponent>().is_none() {
+                warn!(node = %self.node_path, type_path, "type is not a reflectable component; skipping");
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            components.push((registration.type_id(), value));
+        }
+        Ok(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        max: f32,
+        current: f32,
+    }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    enum Faction {
+        Enemy,
+        Player,
+    }
+
+    fn test_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register::<Faction>();
+        registry
+    }
+
+    fn deserialize_all(registry: &TypeRegistry, ron: &str) -> Vec<(TypeId, Box<dyn PartialReflect>)> {
+        let mut deserializer = ron::Deserializer::from_str(ron).unwrap();
+        (ComponentMapDeserializer {
+            registry,
+            node_path: "/test",
+        })
+        .deserialize(&mut deserializer)
+        .unwrap()
+    }
+
+    #[test]
+    fn deserializes_struct_component() {
+        let registry = test_registry();
+        let components = deserialize_all(
+            &registry,
+            r#"{ "godot_bevy::plugins::component_authoring::tests::Health": (max: 100.0, current: 50.0) }"#,
+        );
+
+        assert_eq!(components.len(), 1);
+        let health = Health::from_reflect(components[0].1.as_partial_reflect())
+            .expect("expected Health");
+        assert_eq!(health, Health { max: 100.0, current: 50.0 });
+    }
+
+    #[test]
+    fn deserializes_enum_component() {
+        let registry = test_registry();
+        let components = deserialize_all(
+            &registry,
+            r#"{ "godot_bevy::plugins::component_authoring::tests::Faction": Enemy }"#,
+        );
+
+        assert_eq!(components.len(), 1);
+        let faction = Faction::from_reflect(components[0].1.as_partial_reflect())
+            .expect("expected Faction");
+        assert_eq!(faction, Faction::Enemy);
+    }
+}
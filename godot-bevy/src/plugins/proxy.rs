@@ -0,0 +1,44 @@
+use crate::interop::GodotNodeHandle;
+use crate::plugins::core::PrePhysicsUpdate;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+/// Extension trait for `App` to register proxy-component replacement.
+///
+/// Complements [`AppSceneTreeExt::register_scene_tree_component_with_init`](crate::plugins::core::AppSceneTreeExt::register_scene_tree_component_with_init):
+/// that API can only build a component at spawn time from the Godot node
+/// alone, with no access to data already authored on the entity. Proxy
+/// components close that gap by acting as cheap markers/config (commonly
+/// authored through the [`component_authoring`](crate::plugins::component_authoring)
+/// metadata workflow) that Rust expands into a fully-initialized runtime
+/// bundle once the node is available.
+pub trait AppProxyExt {
+    /// Registers `Proxy` so that, once it appears on a scene-tree entity,
+    /// `replace_fn` is run with the proxy's data and the entity's
+    /// [`GodotNodeHandle`] to build the real runtime components, after which
+    /// the proxy itself is removed.
+    fn register_proxy<Proxy, F>(&mut self, replace_fn: F) -> &mut Self
+    where
+        Proxy: Component,
+        F: Fn(&Proxy, &GodotNodeHandle, &mut EntityCommands) + Send + Sync + 'static;
+}
+
+impl AppProxyExt for App {
+    fn register_proxy<Proxy, F>(&mut self, replace_fn: F) -> &mut Self
+    where
+        Proxy: Component,
+        F: Fn(&Proxy, &GodotNodeHandle, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        self.add_systems(PrePhysicsUpdate, move |mut commands: Commands,
+                                                  proxies: Query<
+            (Entity, &Proxy, &GodotNodeHandle),
+            Added<Proxy>,
+        >| {
+            for (entity, proxy, handle) in &proxies {
+                let mut entity_commands = commands.entity(entity);
+                replace_fn(proxy, handle, &mut entity_commands);
+                entity_commands.remove::<Proxy>();
+            }
+        })
+    }
+}
@@ -0,0 +1,309 @@
+use bevy::prelude::*;
+use bevy::reflect::serde::ReflectSerializer;
+use bevy::reflect::{ReflectRef, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo};
+use serde::Serialize;
+use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+use tracing::{info, warn};
+
+/// Configuration for [`GodotRegistryExportPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct RegistryExportConfig {
+    /// Path the schema is written to, e.g. `user://bevy_components.json`.
+    pub output_path: String,
+    /// Whether to pretty-print the emitted JSON.
+    pub pretty: bool,
+}
+
+impl Default for RegistryExportConfig {
+    fn default() -> Self {
+        Self {
+            output_path: "user://bevy_components.json".to_string(),
+            pretty: true,
+        }
+    }
+}
+
+/// Hash of the set of reflected component `TypeId`s in the `AppTypeRegistry`
+/// as of the last successful export; an export with the same hash is skipped.
+/// The hash is held in memory only and starts empty on every launch, so the
+/// schema file is written once per launch; it is not compared against a file
+/// written by an earlier launch.
+#[derive(Resource, Default)]
+struct RegistrySchemaHash(Option<u64>);
+
+/// Walks the `AppTypeRegistry` at startup and writes a JSON schema describing
+/// every reflected component type to [`RegistryExportConfig::output_path`].
+///
+/// A companion Godot editor plugin can read this file to present typed
+/// dropdowns/fields for the `bevy_components` metadata workflow (see
+/// [`component_authoring`](crate::plugins::component_authoring)) instead of
+/// forcing users to hand-write RON.
+#[derive(Default)]
+pub struct GodotRegistryExportPlugin;
+
+impl Plugin for GodotRegistryExportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RegistryExportConfig>()
+            .init_resource::<RegistrySchemaHash>()
+            .add_systems(Startup, export_registry_schema);
+    }
+}
+
+#[derive(Serialize)]
+struct ComponentSchema {
+    type_path: String,
+    kind: TypeKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<FieldSchema>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    variants: Vec<VariantSchema>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum TypeKind {
+    Struct,
+    TupleStruct,
+    Enum,
+    Value,
+}
+
+/// One enum variant: its name plus its payload fields (empty for a unit variant).
+#[derive(Serialize)]
+struct VariantSchema {
+    name: String,
+    fields: Vec<FieldSchema>,
+}
+
+#[derive(Serialize)]
+struct FieldSchema {
+    name: String,
+    field_type: FieldType,
+    default_value: Option<serde_json::Value>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+enum FieldType {
+    Primitive { type_path: String },
+    Option { inner: String },
+    Vec { inner: String },
+    Enum { variants: Vec<String> },
+    Other { type_path: String },
+}
+
+fn export_registry_schema(
+    registry: Res<AppTypeRegistry>,
+    config: Res<RegistryExportConfig>,
+    mut schema_hash: ResMut<RegistrySchemaHash>,
+) {
+    let registry = registry.read();
+
+    let type_ids: BTreeSet<TypeId> = registry
+        .iter()
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(|registration| registration.type_id())
+        .collect();
+
+    let mut hasher = DefaultHasher::new();
+    for type_id in &type_ids {
+        type_id.hash(&mut hasher);
+    }
+    let hash = hasher.finish();
+
+    if schema_hash.0 == Some(hash) {
+        return;
+    }
+
+    let schemas: Vec<ComponentSchema> = type_ids
+        .iter()
+        .filter_map(|type_id| registry.get(*type_id))
+        .map(|registration| component_schema(registration, &registry))
+        .collect();
+
+    let json = if config.pretty {
+        serde_json::to_string_pretty(&schemas)
+    } else {
+        serde_json::to_string(&schemas)
+    };
+
+    let json = match json {
+        Ok(json) => json,
+        Err(err) => {
+            warn!(error = %err, "failed to serialize component registry schema");
+            return;
+        }
+    };
+
+    let mut file = godot::classes::FileAccess::open(
+        &config.output_path,
+        godot::classes::file_access::ModeFlags::WRITE,
+    );
+    match file.as_mut() {
+        Some(file) => {
+            file.store_string(&json);
+            schema_hash.0 = Some(hash);
+            info!(path = %config.output_path, count = schemas.len(), "exported component registry schema");
+        }
+        None => warn!(path = %config.output_path, "failed to open component registry schema file for writing"),
+    }
+}
+
+fn component_schema(registration: &TypeRegistration, registry: &TypeRegistry) -> ComponentSchema {
+    let type_info = registration.type_info();
+    let type_path = type_info.type_path().to_string();
+
+    // Read defaults off the component's own `Default` impl rather than each
+    // field's type: a field's type-level default (e.g. `f32::default() == 0.0`)
+    // can easily differ from what the component itself initializes that field
+    // to (e.g. `Health { current: 100.0, .. }`).
+    let default_instance = registration
+        .data::<ReflectDefault>()
+        .map(|reflect_default| reflect_default.default());
+
+    match type_info {
+        TypeInfo::Struct(info) => ComponentSchema {
+            type_path,
+            kind: TypeKind::Struct,
+            fields: info
+                .iter()
+                .map(|field| {
+                    let default_value = struct_field_default(default_instance.as_deref(), field.name(), registry);
+                    field_schema(field.name().to_string(), field.type_path(), default_value, registry)
+                })
+                .collect(),
+            variants: Vec::new(),
+        },
+        TypeInfo::TupleStruct(info) => ComponentSchema {
+            type_path,
+            kind: TypeKind::TupleStruct,
+            fields: info
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    let default_value = tuple_struct_field_default(default_instance.as_deref(), index, registry);
+                    field_schema(index.to_string(), field.type_path(), default_value, registry)
+                })
+                .collect(),
+            variants: Vec::new(),
+        },
+        TypeInfo::Enum(info) => ComponentSchema {
+            type_path,
+            kind: TypeKind::Enum,
+            fields: Vec::new(),
+            variants: info
+                .iter()
+                .map(|variant| variant_schema(variant, registry))
+                .collect(),
+        },
+        _ => ComponentSchema {
+            type_path,
+            kind: TypeKind::Value,
+            fields: Vec::new(),
+            variants: Vec::new(),
+        },
+    }
+}
+
+/// Reads `field_name`'s value off the component's own default instance (if it
+/// has one and is struct-shaped), serialized for the schema's `default_value`.
+fn struct_field_default(
+    default_instance: Option<&dyn Reflect>,
+    field_name: &str,
+    registry: &TypeRegistry,
+) -> Option<serde_json::Value> {
+    let ReflectRef::Struct(instance) = default_instance?.reflect_ref() else {
+        return None;
+    };
+    let field = instance.field(field_name)?;
+    serde_json::to_value(ReflectSerializer::new(field, registry)).ok()
+}
+
+/// Tuple-struct counterpart of [`struct_field_default`], indexing by field
+/// position instead of name.
+fn tuple_struct_field_default(
+    default_instance: Option<&dyn Reflect>,
+    index: usize,
+    registry: &TypeRegistry,
+) -> Option<serde_json::Value> {
+    let ReflectRef::TupleStruct(instance) = default_instance?.reflect_ref() else {
+        return None;
+    };
+    let field = instance.field(index)?;
+    serde_json::to_value(ReflectSerializer::new(field, registry)).ok()
+}
+
+/// Builds the schema for a single enum variant, including its payload fields
+/// for tuple/struct variants (empty for a unit variant).
+fn variant_schema(variant: &VariantInfo, registry: &TypeRegistry) -> VariantSchema {
+    let fields = match variant {
+        VariantInfo::Struct(info) => info
+            .iter()
+            .map(|field| field_schema(field.name().to_string(), field.type_path(), None, registry))
+            .collect(),
+        VariantInfo::Tuple(info) => info
+            .iter()
+            .enumerate()
+            .map(|(index, field)| field_schema(index.to_string(), field.type_path(), None, registry))
+            .collect(),
+        VariantInfo::Unit(_) => Vec::new(),
+    };
+
+    VariantSchema {
+        name: variant.name().to_string(),
+        fields,
+    }
+}
+
+fn field_schema(
+    name: String,
+    type_path: &str,
+    default_value: Option<serde_json::Value>,
+    registry: &TypeRegistry,
+) -> FieldSchema {
+    let field_type = if let Some(inner) = type_path
+        .strip_prefix("core::option::Option<")
+        .and_then(|s| s.strip_suffix('>'))
+    {
+        FieldType::Option {
+            inner: inner.to_string(),
+        }
+    } else if let Some(inner) = type_path
+        .strip_prefix("alloc::vec::Vec<")
+        .and_then(|s| s.strip_suffix('>'))
+    {
+        FieldType::Vec {
+            inner: inner.to_string(),
+        }
+    } else if matches!(
+        type_path,
+        "bool" | "f32" | "f64" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "alloc::string::String" | "str"
+    ) {
+        FieldType::Primitive {
+            type_path: type_path.to_string(),
+        }
+    } else if let Some(TypeInfo::Enum(info)) = registry
+        .get_with_type_path(type_path)
+        .map(|registration| registration.type_info())
+    {
+        FieldType::Enum {
+            variants: info.iter().map(|v| v.name().to_string()).collect(),
+        }
+    } else {
+        FieldType::Other {
+            type_path: type_path.to_string(),
+        }
+    };
+
+    let default_value = default_value.filter(|_| !matches!(field_type, FieldType::Enum { .. }));
+
+    FieldSchema {
+        name,
+        field_type,
+        default_value,
+    }
+}
+
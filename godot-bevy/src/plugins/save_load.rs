@@ -0,0 +1,505 @@
+use crate::interop::GodotNodeHandle;
+use crate::plugins::packed_scene::{GodotPackedScenePlugin, GodotScene};
+use bevy::hierarchy::BuildWorldChildren;
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::{DynamicSceneBuilder, SceneFilter};
+use godot::prelude::*;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::{info, warn};
+
+/// Stable identifier persisted on every saved entity so a restored entity can
+/// be matched back to the gameplay components that were saved for it, since
+/// `Entity` ids are not preserved across a save/load round-trip.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct PersistentId(pub u64);
+
+/// Configuration for [`GodotSaveLoadPlugin`].
+#[derive(Resource, Clone)]
+pub struct SaveConfig {
+    /// Component types to include when snapshotting the world.
+    pub component_filter: SceneFilter,
+    /// Resource types to include when snapshotting the world.
+    pub resource_filter: SceneFilter,
+    /// Directory (under `user://`) that relative save paths are resolved against.
+    pub save_root: String,
+}
+
+impl Default for SaveConfig {
+    fn default() -> Self {
+        Self {
+            component_filter: SceneFilter::default(),
+            resource_filter: SceneFilter::default(),
+            save_root: "user://saves".to_string(),
+        }
+    }
+}
+
+/// Requests that the world be snapshotted to `path` (resolved against
+/// [`SaveConfig::save_root`] if relative).
+#[derive(Event, Debug, Clone)]
+pub struct SaveRequest {
+    pub path: String,
+}
+
+/// Requests that the world be restored from `path`.
+#[derive(Event, Debug, Clone)]
+pub struct LoadRequest {
+    pub path: String,
+}
+
+/// Fired once a [`SaveRequest`] has finished writing its file.
+#[derive(Event, Debug, Clone)]
+pub struct SaveComplete {
+    pub path: String,
+}
+
+/// Snapshots the ECS world to `user://saves/*.ron` and restores it later,
+/// reconstructing the Godot nodes a save file refers to.
+///
+/// Entities that are pure scene-tree mirrors (their [`GodotNodeHandle`] will
+/// simply be re-instantiated from a [`GodotScene`] blueprint) are not
+/// serialized in full; instead each one contributes a lightweight record of
+/// its blueprint path plus its gameplay components, and is re-created by
+/// [`GodotPackedScenePlugin`] on load before those components are re-applied.
+///
+/// Only entities carrying [`PersistentId`] are captured by a save. Every
+/// scene-tree entity gets one automatically; an entity with no
+/// `GodotNodeHandle` must insert `PersistentId` itself to opt into saving.
+#[derive(Default)]
+pub struct GodotSaveLoadPlugin;
+
+impl Plugin for GodotSaveLoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveConfig>()
+            .init_resource::<NextPersistentId>()
+            .add_event::<SaveRequest>()
+            .add_event::<LoadRequest>()
+            .add_event::<SaveComplete>()
+            .add_systems(PreUpdate, assign_persistent_ids)
+            .add_systems(Update, (handle_save_requests, handle_load_requests));
+    }
+}
+
+/// Counter backing automatic [`PersistentId`] assignment.
+#[derive(Resource, Default)]
+struct NextPersistentId(u64);
+
+/// Stamps a fresh [`PersistentId`] onto every scene-tree entity that doesn't
+/// already have one, so a save captures it without the caller needing to
+/// assign the id by hand.
+fn assign_persistent_ids(
+    mut commands: Commands,
+    mut next_id: ResMut<NextPersistentId>,
+    unassigned: Query<Entity, (With<GodotNodeHandle>, Without<PersistentId>)>,
+) {
+    for entity in &unassigned {
+        commands.entity(entity).insert(PersistentId(next_id.0));
+        next_id.0 += 1;
+    }
+}
+
+/// Locates a saved entity that is itself a scene-tree mirror of a node inside
+/// a blueprint another saved entity re-instantiates, rather than a blueprint
+/// root in its own right: `root_id` is that root's stable id, and
+/// `relative_node_path` is this entity's node path relative to the root's.
+#[derive(Serialize, Deserialize)]
+struct MirrorRef {
+    root_id: u64,
+    relative_node_path: String,
+}
+
+/// One saved entity: its stable id, the blueprint it should be re-instantiated
+/// from (if it is a blueprint root), the mirror reference used to reconcile
+/// it against an already re-instantiated subtree (if it is a mirror child),
+/// its parent's stable id (if the parent is also part of this save), and its
+/// gameplay components encoded as a single-entity dynamic-scene fragment in RON.
+#[derive(Serialize, Deserialize)]
+struct SavedEntity {
+    id: u64,
+    blueprint_path: Option<String>,
+    mirror_of: Option<MirrorRef>,
+    parent_id: Option<u64>,
+    components_ron: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    entities: Vec<SavedEntity>,
+    resources_ron: String,
+}
+
+fn handle_save_requests(world: &mut World) {
+    let requests: Vec<SaveRequest> = world.resource_mut::<Events<SaveRequest>>().drain().collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    let config = world.resource::<SaveConfig>().clone();
+
+    for request in requests {
+        let save_file = build_save_file(world, &config);
+
+        let Ok(ron_string) = ron::to_string(&save_file) else {
+            warn!(path = %request.path, "failed to encode save file");
+            continue;
+        };
+
+        let path = resolve_path(&config, &request.path);
+        let mut file =
+            godot::classes::FileAccess::open(&path, godot::classes::file_access::ModeFlags::WRITE);
+        match file.as_mut() {
+            Some(file) => {
+                file.store_string(&ron_string);
+                info!(path = %path, entities = save_file.entities.len(), "saved world");
+                world.send_event(SaveComplete { path });
+            }
+            None => warn!(path = %path, "failed to open save file for writing"),
+        }
+    }
+}
+
+/// Builds the on-disk save file, pruning `GodotNodeHandle`/`GodotScene` out of
+/// each entity's serialized components (they are reconstructed from
+/// `blueprint_path`/`mirror_of` instead) and remapping each entity's `Parent`
+/// to the stable id of its own parent -- or dropping the link if that parent
+/// isn't itself part of this save, rather than serializing a reference that
+/// would dangle once entities get new ids on load.
+fn build_save_file(world: &mut World, config: &SaveConfig) -> SaveFile {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+
+    let mut query = world.query::<(
+        Entity,
+        &PersistentId,
+        Option<&GodotScene>,
+        Option<&Parent>,
+        Option<&GodotNodeHandle>,
+    )>();
+    let entries: Vec<(Entity, u64, Option<String>, Option<Entity>, Option<String>)> = query
+        .iter(world)
+        .map(|(entity, id, scene, parent, handle)| {
+            (
+                entity,
+                id.0,
+                scene.map(|scene| scene.path.clone()),
+                parent.map(|parent| parent.get()),
+                handle.map(|handle| handle.get::<Node>().get_path().to_string()),
+            )
+        })
+        .collect();
+
+    let entity_to_id: HashMap<Entity, u64> =
+        entries.iter().map(|(entity, id, _, _, _)| (*entity, *id)).collect();
+    let entity_to_parent: HashMap<Entity, Entity> = entries
+        .iter()
+        .filter_map(|(entity, _, _, parent, _)| parent.map(|parent| (*entity, parent)))
+        .collect();
+    let blueprint_root_entities: HashSet<Entity> = entries
+        .iter()
+        .filter(|(_, _, scene, _, _)| scene.is_some())
+        .map(|(entity, _, _, _, _)| *entity)
+        .collect();
+    let node_paths: HashMap<Entity, String> = entries
+        .iter()
+        .filter_map(|(entity, _, _, _, path)| path.clone().map(|path| (*entity, path)))
+        .collect();
+
+    // A mirror child is a scene-tree entity that isn't a blueprint root
+    // itself; find the nearest ancestor that is one so it can be reconciled
+    // against that blueprint's re-instantiated subtree on load, instead of
+    // being respawned as a bare, node-less duplicate.
+    let mirror_of = |entity: Entity| -> Option<MirrorRef> {
+        if blueprint_root_entities.contains(&entity) {
+            return None;
+        }
+        let node_path = node_paths.get(&entity)?;
+
+        let mut ancestor = entity_to_parent.get(&entity).copied();
+        while let Some(candidate) = ancestor {
+            if blueprint_root_entities.contains(&candidate) {
+                let root_node_path = node_paths.get(&candidate)?;
+                let relative_node_path = node_path
+                    .strip_prefix(&format!("{root_node_path}/"))
+                    .unwrap_or(node_path)
+                    .to_string();
+                return Some(MirrorRef {
+                    root_id: entity_to_id[&candidate],
+                    relative_node_path,
+                });
+            }
+            ancestor = entity_to_parent.get(&candidate).copied();
+        }
+        None
+    };
+
+    let entities = entries
+        .into_iter()
+        .map(|(entity, id, blueprint_path, parent, _)| {
+            let parent_id = parent.and_then(|parent| entity_to_id.get(&parent).copied());
+            let mirror_of = mirror_of(entity);
+
+            // Hierarchy is round-tripped out-of-band via `parent_id` above
+            // (stable ids survive a reload, raw `Entity` references don't),
+            // so the components themselves never need `Parent`/`Children`.
+            let scene = DynamicSceneBuilder::from_world(world)
+                .with_filter(config.component_filter.clone())
+                .deny::<GodotNodeHandle>()
+                .deny::<GodotScene>()
+                .deny::<Parent>()
+                .deny::<Children>()
+                .extract_entity(entity)
+                .remove_empty_entities()
+                .build();
+
+            let components_ron = scene
+                .serialize(&type_registry.read())
+                .unwrap_or_default();
+
+            SavedEntity {
+                id,
+                blueprint_path,
+                mirror_of,
+                parent_id,
+                components_ron,
+            }
+        })
+        .collect();
+
+    let resources_scene = DynamicSceneBuilder::from_world(world)
+        .with_resource_filter(config.resource_filter.clone())
+        .extract_resources()
+        .build();
+    let resources_ron = resources_scene
+        .serialize(&type_registry.read())
+        .unwrap_or_default();
+
+    SaveFile {
+        entities,
+        resources_ron,
+    }
+}
+
+fn handle_load_requests(world: &mut World) {
+    let requests: Vec<LoadRequest> = world.resource_mut::<Events<LoadRequest>>().drain().collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    let config = world.resource::<SaveConfig>().clone();
+
+    for request in requests {
+        let path = resolve_path(&config, &request.path);
+        let Some(mut file) =
+            godot::classes::FileAccess::open(&path, godot::classes::file_access::ModeFlags::READ)
+        else {
+            warn!(path = %path, "failed to open save file for reading");
+            continue;
+        };
+        let ron_string = file.get_as_text().to_string();
+
+        let save_file: SaveFile = match ron::from_str(&ron_string) {
+            Ok(save_file) => save_file,
+            Err(err) => {
+                warn!(path = %path, error = %err, "malformed save file");
+                continue;
+            }
+        };
+
+        clear_persistent_entities(world);
+
+        // Ids restored from this file must never be handed out again by
+        // `assign_persistent_ids`, or a freshly spawned entity could collide
+        // with one this load just recreated.
+        if let Some(max_loaded_id) = save_file.entities.iter().map(|saved| saved.id).max() {
+            let mut next_id = world.resource_mut::<NextPersistentId>();
+            next_id.0 = next_id.0.max(max_loaded_id + 1);
+        }
+
+        let mut id_to_entity: HashMap<u64, Entity> = HashMap::new();
+
+        // Pass 1: instantiate every blueprint root, recording the node-path ->
+        // entity map of the subtree it spawned so mirror children (pass 2) can
+        // be reconciled against it instead of respawned as bare duplicates.
+        let mut spawned_subtrees: HashMap<u64, (String, HashMap<String, Entity>)> = HashMap::new();
+        for saved in &save_file.entities {
+            let Some(blueprint_path) = &saved.blueprint_path else {
+                continue;
+            };
+
+            let existing: HashSet<Entity> = world.iter_entities().map(|entity_ref| entity_ref.id()).collect();
+            let root_entity = GodotPackedScenePlugin::instantiate(world, blueprint_path);
+            world.entity_mut(root_entity).insert(PersistentId(saved.id));
+            id_to_entity.insert(saved.id, root_entity);
+
+            let Some(root_node_path) = world
+                .get::<GodotNodeHandle>(root_entity)
+                .map(|handle| handle.get::<Node>().get_path().to_string())
+            else {
+                continue;
+            };
+
+            let spawned_by_path: HashMap<String, Entity> = world
+                .iter_entities()
+                .filter(|entity_ref| !existing.contains(&entity_ref.id()))
+                .filter_map(|entity_ref| {
+                    let handle = entity_ref.get::<GodotNodeHandle>()?;
+                    Some((handle.get::<Node>().get_path().to_string(), entity_ref.id()))
+                })
+                .collect();
+            spawned_subtrees.insert(saved.id, (root_node_path, spawned_by_path));
+        }
+
+        // Pass 2: reconcile mirror children against the subtree their
+        // blueprint root just spawned, falling back to a bare entity if the
+        // blueprint no longer has a node at that path.
+        for saved in &save_file.entities {
+            let Some(mirror) = &saved.mirror_of else {
+                continue;
+            };
+            let entity = spawned_subtrees
+                .get(&mirror.root_id)
+                .and_then(|(root_node_path, by_path)| {
+                    by_path
+                        .get(&format!("{root_node_path}/{}", mirror.relative_node_path))
+                        .copied()
+                })
+                .unwrap_or_else(|| world.spawn_empty().id());
+            world.entity_mut(entity).insert(PersistentId(saved.id));
+            id_to_entity.insert(saved.id, entity);
+        }
+
+        // Pass 3: anything left over is a standalone gameplay entity with no
+        // Godot node of its own.
+        for saved in &save_file.entities {
+            if id_to_entity.contains_key(&saved.id) {
+                continue;
+            }
+            let entity = world.spawn_empty().id();
+            world.entity_mut(entity).insert(PersistentId(saved.id));
+            id_to_entity.insert(saved.id, entity);
+        }
+
+        for saved in &save_file.entities {
+            let entity = id_to_entity[&saved.id];
+            apply_saved_components(world, entity, &saved.components_ron, &path);
+        }
+
+        for saved in &save_file.entities {
+            let Some(parent_id) = saved.parent_id else {
+                continue;
+            };
+            let Some(&parent) = id_to_entity.get(&parent_id) else {
+                continue;
+            };
+            let child = id_to_entity[&saved.id];
+            world.entity_mut(child).set_parent(parent);
+        }
+
+        apply_saved_resources(world, &save_file.resources_ron, &path);
+
+        info!(path = %path, entities = save_file.entities.len(), "loaded world");
+    }
+}
+
+fn apply_saved_components(world: &mut World, entity: Entity, components_ron: &str, path: &str) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+
+    let mut deserializer = match ron::Deserializer::from_str(components_ron) {
+        Ok(deserializer) => deserializer,
+        Err(err) => {
+            warn!(path = %path, error = %err, "malformed component data for saved entity; skipping");
+            return;
+        }
+    };
+    let scene = match (SceneDeserializer { type_registry: &registry }).deserialize(&mut deserializer) {
+        Ok(scene) => scene,
+        Err(err) => {
+            warn!(path = %path, error = %err, "failed to deserialize saved entity; skipping");
+            return;
+        }
+    };
+
+    let Some(source) = scene.entities.first() else {
+        return;
+    };
+    for component in &source.components {
+        let Some(reflect_component) = registry
+            .get(component.type_id())
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            continue;
+        };
+        let mut entity_mut = world.entity_mut(entity);
+        reflect_component.apply_or_insert(&mut entity_mut, component.as_partial_reflect(), &registry);
+    }
+}
+
+fn apply_saved_resources(world: &mut World, resources_ron: &str, path: &str) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+
+    let mut deserializer = match ron::Deserializer::from_str(resources_ron) {
+        Ok(deserializer) => deserializer,
+        Err(err) => {
+            warn!(path = %path, error = %err, "malformed resource data in save file; skipping resources");
+            return;
+        }
+    };
+    let scene = match (SceneDeserializer { type_registry: &registry }).deserialize(&mut deserializer) {
+        Ok(scene) => scene,
+        Err(err) => {
+            warn!(path = %path, error = %err, "failed to deserialize saved resources; skipping");
+            return;
+        }
+    };
+
+    for resource in &scene.resources {
+        let Some(reflect_resource) = registry
+            .get(resource.type_id())
+            .and_then(|registration| registration.data::<ReflectResource>())
+        else {
+            continue;
+        };
+        reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &registry);
+    }
+}
+
+/// Frees the Godot node behind every previously loaded persistent entity and
+/// recursively despawns the entities themselves, so a reload doesn't leave
+/// the previous scene-tree subtree (and its now-orphaned child entities)
+/// behind alongside the freshly re-instantiated one.
+fn clear_persistent_entities(world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<PersistentId>>();
+    let roots: Vec<Entity> = query.iter(world).collect();
+    for entity in roots {
+        despawn_recursive(world, entity);
+    }
+}
+
+fn despawn_recursive(world: &mut World, entity: Entity) {
+    let children: Vec<Entity> = world
+        .get::<Children>(entity)
+        .map(|children| children.iter().copied().collect())
+        .unwrap_or_default();
+    for child in children {
+        despawn_recursive(world, child);
+    }
+
+    if let Some(handle) = world.get::<GodotNodeHandle>(entity) {
+        let mut node = handle.get::<Node>();
+        node.queue_free();
+    }
+    world.despawn(entity);
+}
+
+fn resolve_path(config: &SaveConfig, path: &str) -> String {
+    if path.starts_with("user://") || path.starts_with("res://") {
+        path.to_string()
+    } else {
+        format!("{}/{}", config.save_root.trim_end_matches('/'), path)
+    }
+}